@@ -1,10 +1,50 @@
-use core::{iter::Enumerate, str::Lines};
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CsvDate {
+    pub year: u16,
+    pub month: u8,
+    pub day: u8,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CsvTime {
+    pub hour: u8,
+    pub minute: u8,
+    pub second: u8,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CsvDateTime {
+    pub date: CsvDate,
+    pub time: Option<CsvTime>,
+}
 
 #[derive(Debug)]
 pub enum CsvValue {
     Text(String),
     Integer(i64),
     Float(f64),
+    Boolean(bool),
+    DateTime(CsvDateTime),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ColumnType {
+    Integer,
+    Float,
+    Boolean,
+    DateTime,
+    Text,
+}
+
+#[inline]
+fn value_column_type(value: &CsvValue) -> ColumnType {
+    match value {
+        CsvValue::Integer(_) => ColumnType::Integer,
+        CsvValue::Float(_) => ColumnType::Float,
+        CsvValue::Boolean(_) => ColumnType::Boolean,
+        CsvValue::DateTime(_) => ColumnType::DateTime,
+        CsvValue::Text(_) => ColumnType::Text,
+    }
 }
 
 #[inline]
@@ -19,7 +59,10 @@ fn handle_new_key(key: &str, len: usize) -> String {
 }
 
 #[inline]
-fn parse_header(lines: &mut Enumerate<Lines>, separator: char) -> Option<Vec<String>> {
+fn parse_header<'a>(
+    lines: &mut impl Iterator<Item = (usize, &'a str)>,
+    separator: char,
+) -> Option<Vec<String>> {
     for (_, line) in lines {
         let mut keys = Vec::new();
 
@@ -178,6 +221,101 @@ mod test_parse_header {
     }
 }
 
+#[inline]
+fn parse_boolean(value: &str) -> Option<bool> {
+    match value.to_ascii_lowercase().as_str() {
+        "true" | "yes" => Some(true),
+        "false" | "no" => Some(false),
+        _ => None,
+    }
+}
+
+#[inline]
+fn parse_date(value: &str) -> Option<CsvDate> {
+    let mut parts = value.splitn(3, '-');
+
+    let year = parts.next()?;
+    let month = parts.next()?;
+    let day = parts.next()?;
+
+    if year.len() != 4 || month.len() != 2 || day.len() != 2 {
+        return None;
+    }
+
+    let year = year.parse::<u16>().ok()?;
+    let month = month.parse::<u8>().ok()?;
+    let day = day.parse::<u8>().ok()?;
+
+    if !(1..=12).contains(&month) || !(1..=31).contains(&day) {
+        return None;
+    }
+
+    Some(CsvDate { year, month, day })
+}
+
+#[inline]
+fn parse_time(value: &str) -> Option<CsvTime> {
+    let without_offset = value
+        .split_once(['+', '-'])
+        .map_or(value, |(time, _)| time)
+        .trim_end_matches(['Z', 'z']);
+
+    let without_fraction = without_offset
+        .split_once('.')
+        .map_or(without_offset, |(time, _)| time);
+
+    let mut parts = without_fraction.splitn(3, ':');
+
+    let hour = parts.next()?;
+    let minute = parts.next()?;
+    let second = parts.next()?;
+
+    if hour.len() != 2 || minute.len() != 2 || second.len() != 2 {
+        return None;
+    }
+
+    let hour = hour.parse::<u8>().ok()?;
+    let minute = minute.parse::<u8>().ok()?;
+    let second = second.parse::<u8>().ok()?;
+
+    if hour > 23 || minute > 59 || second > 60 {
+        return None;
+    }
+
+    Some(CsvTime {
+        hour,
+        minute,
+        second,
+    })
+}
+
+#[inline]
+fn parse_datetime(value: &str) -> Option<CsvDateTime> {
+    let mut char_indices = value.char_indices();
+
+    for _ in 0..10 {
+        char_indices.next()?;
+    }
+
+    let split_at = char_indices.next().map_or(value.len(), |(index, _)| index);
+
+    let (date_part, time_part) = value.split_at(split_at);
+
+    let date = parse_date(date_part)?;
+
+    if time_part.is_empty() {
+        return Some(CsvDateTime { date, time: None });
+    }
+
+    let time_part = time_part.strip_prefix(['T', 't', ' '])?;
+    let time = parse_time(time_part)?;
+
+    Some(CsvDateTime {
+        date,
+        time: Some(time),
+    })
+}
+
 #[inline]
 fn parse_value(value: &str) -> Option<CsvValue> {
     let trimmed_line = value.trim();
@@ -190,6 +328,14 @@ fn parse_value(value: &str) -> Option<CsvValue> {
         return Some(CsvValue::Integer(integer));
     }
 
+    if let Some(boolean) = parse_boolean(trimmed_line) {
+        return Some(CsvValue::Boolean(boolean));
+    }
+
+    if let Some(datetime) = parse_datetime(trimmed_line) {
+        return Some(CsvValue::DateTime(datetime));
+    }
+
     if let Ok(maybe_float) = trimmed_line.parse::<f64>() {
         return Some(CsvValue::Float(maybe_float));
     }
@@ -255,6 +401,83 @@ mod test_parse_value {
 
         assert!(matches!(result, CsvValue::Float(value) if value == 0.0f64));
     }
+
+    #[test]
+    fn it_should_understand_scientific_notation_as_a_float() {
+        let values = ["1.5e10", "-3E-2", "6.022e23"];
+
+        for value in values {
+            let result = parse_value(value).expect("it to be some");
+
+            let expected = value.parse::<f64>().expect("it to be a valid float");
+            assert!(matches!(result, CsvValue::Float(v) if (v - expected).abs() < f64::EPSILON));
+        }
+    }
+
+    #[test]
+    fn it_should_understand_boolean_values_case_insensitively() {
+        let trues = ["true", "TRUE", "True", "yes", "YES"];
+        let falses = ["false", "FALSE", "False", "no", "NO"];
+
+        for value in trues {
+            let result = parse_value(value).expect("it to be some");
+            assert!(matches!(result, CsvValue::Boolean(v) if v));
+        }
+
+        for value in falses {
+            let result = parse_value(value).expect("it to be some");
+            assert!(matches!(result, CsvValue::Boolean(v) if !v));
+        }
+    }
+
+    #[test]
+    fn it_should_understand_a_plain_date() {
+        let result = parse_value("2024-03-15").expect("it to be some");
+
+        assert!(matches!(
+            result,
+            CsvValue::DateTime(dt) if dt.date.year == 2024 && dt.date.month == 3 && dt.date.day == 15 && dt.time.is_none()
+        ));
+    }
+
+    #[test]
+    fn it_should_understand_an_rfc3339_datetime() {
+        let values = [
+            "2024-03-15T13:45:30",
+            "2024-03-15T13:45:30Z",
+            "2024-03-15T13:45:30.123Z",
+            "2024-03-15T13:45:30+02:00",
+            "2024-03-15 13:45:30",
+        ];
+
+        for value in values {
+            let result = parse_value(value).expect("it to be some");
+
+            assert!(matches!(
+                result,
+                CsvValue::DateTime(dt) if dt.date.year == 2024
+                    && dt.date.month == 3
+                    && dt.date.day == 15
+                    && dt.time == Some(crate::CsvTime { hour: 13, minute: 45, second: 30 })
+            ));
+        }
+    }
+
+    #[test]
+    fn it_should_not_mistake_a_regular_integer_or_float_for_a_datetime() {
+        let result = parse_value("20240315").expect("it to be some");
+        assert!(matches!(result, CsvValue::Integer(v) if v == 20_240_315));
+
+        let result = parse_value("1.5e10").expect("it to be some");
+        assert!(matches!(result, CsvValue::Float(_)));
+    }
+
+    #[test]
+    fn it_should_not_panic_on_a_multibyte_text_value_straddling_the_date_boundary() {
+        let result = parse_value("日本語テスト").expect("it to be some");
+
+        assert!(matches!(result, CsvValue::Text(v) if v == "日本語テスト"));
+    }
 }
 
 #[inline]
@@ -290,6 +513,19 @@ mod test_get_value_field {
     }
 }
 
+#[inline]
+fn parse_field_value(current_value: &str, quoted_field: bool) -> Option<CsvValue> {
+    if quoted_field {
+        if current_value.is_empty() {
+            return None;
+        }
+
+        return Some(CsvValue::Text(current_value.to_owned()));
+    }
+
+    parse_value(current_value)
+}
+
 #[inline]
 fn parse_value_line(
     line: &str,
@@ -299,23 +535,42 @@ fn parse_value_line(
     let mut values = std::collections::HashMap::new();
 
     let mut current_value = String::new();
+    let mut in_quotes = false;
+    let mut quoted_field = false;
 
     let mut index = 0;
 
-    for ch in line.chars() {
-        if ch == separator {
-            if let Some(value) = parse_value(&current_value) {
+    let mut chars = line.chars().peekable();
+
+    while let Some(ch) = chars.next() {
+        if in_quotes {
+            if ch == '"' {
+                if chars.peek() == Some(&'"') {
+                    current_value.push('"');
+                    chars.next();
+                } else {
+                    in_quotes = false;
+                }
+            } else {
+                current_value.push(ch);
+            }
+        } else if ch == '"' && current_value.is_empty() && !quoted_field {
+            in_quotes = true;
+            quoted_field = true;
+        } else if ch == separator {
+            if let Some(value) = parse_field_value(&current_value, quoted_field) {
                 values.insert(get_value_field(fields, index), value);
             }
 
             current_value.clear();
+            quoted_field = false;
             index += 1;
         } else {
             current_value.push(ch);
         }
     }
 
-    if let Some(value) = parse_value(&current_value) {
+    if let Some(value) = parse_field_value(&current_value, quoted_field) {
         values.insert(get_value_field(fields, index), value);
     }
 
@@ -368,6 +623,71 @@ mod test_parse_value_line {
         }
     }
 
+    #[test]
+    fn it_should_treat_a_separator_inside_quotes_as_literal() {
+        let fields = vec!["key 1".to_owned(), "key 2".to_owned()];
+
+        let line = "\"a,b\",c";
+
+        let result = parse_value_line(line, ',', &fields);
+
+        let one = result.get("key 1").expect("it to be some");
+        assert!(matches!(one, CsvValue::Text(value) if value == "a,b"));
+
+        let two = result.get("key 2").expect("it to be some");
+        assert!(matches!(two, CsvValue::Text(value) if value == "c"));
+    }
+
+    #[test]
+    fn it_should_decode_a_doubled_quote_as_a_literal_quote() {
+        let fields = vec!["key 1".to_owned()];
+
+        let line = "\"she said \"\"hi\"\"\"";
+
+        let result = parse_value_line(line, ',', &fields);
+
+        let one = result.get("key 1").expect("it to be some");
+        assert!(matches!(one, CsvValue::Text(value) if value == "she said \"hi\""));
+    }
+
+    #[test]
+    fn it_should_understand_an_empty_quoted_field() {
+        let fields = vec!["key 1".to_owned(), "key 2".to_owned()];
+
+        let line = "\"\",value";
+
+        let result = parse_value_line(line, ',', &fields);
+
+        assert!(result.get("key 1").is_none());
+
+        let two = result.get("key 2").expect("it to be some");
+        assert!(matches!(two, CsvValue::Text(value) if value == "value"));
+    }
+
+    #[test]
+    fn it_should_keep_surrounding_whitespace_inside_a_quoted_field_literal() {
+        let fields = vec!["key 1".to_owned()];
+
+        let line = "\"  hello  \"";
+
+        let result = parse_value_line(line, ',', &fields);
+
+        let one = result.get("key 1").expect("it to be some");
+        assert!(matches!(one, CsvValue::Text(value) if value == "  hello  "));
+    }
+
+    #[test]
+    fn it_should_not_infer_a_number_out_of_a_quoted_field() {
+        let fields = vec!["key 1".to_owned()];
+
+        let line = "\"123\"";
+
+        let result = parse_value_line(line, ',', &fields);
+
+        let one = result.get("key 1").expect("it to be some");
+        assert!(matches!(one, CsvValue::Text(value) if value == "123"));
+    }
+
     #[test]
     fn it_should_generate_unknown_field_names() {
         let fields = Vec::new();
@@ -398,22 +718,241 @@ mod test_parse_value_line {
 }
 
 #[inline]
-pub fn parse_csv(input: &str, separator: char) -> Vec<std::collections::HashMap<String, CsvValue>> {
-    let mut output = Vec::new();
+fn next_raw_record(input: &str, start: usize) -> Option<(&str, usize)> {
+    if start >= input.len() {
+        return None;
+    }
+
+    let rest = &input[start..];
 
-    let mut lines = input.lines().enumerate();
+    let mut in_quotes = false;
+    let mut end = rest.len();
+    let mut next_start = rest.len();
 
-    if let Some(fields) = parse_header(&mut lines, separator) {
-        for (_, line) in lines {
-            let trimmed_line = line.trim();
+    let mut chars = rest.char_indices().peekable();
 
-            if !trimmed_line.is_empty() {
-                output.push(parse_value_line(trimmed_line, separator, &fields));
+    while let Some((i, ch)) = chars.next() {
+        match ch {
+            '"' => in_quotes = !in_quotes,
+            '\r' if !in_quotes => {
+                end = i;
+                next_start = i + 1;
+
+                if let Some(&(j, '\n')) = chars.peek() {
+                    next_start = j + 1;
+                }
+
+                break;
             }
+            '\n' if !in_quotes => {
+                end = i;
+                next_start = i + 1;
+                break;
+            }
+            _ => {}
         }
     }
 
-    output
+    Some((&rest[..end], start + next_start))
+}
+
+#[cfg(test)]
+mod test_next_raw_record {
+    use crate::next_raw_record;
+
+    #[test]
+    fn it_should_split_on_unquoted_newlines() {
+        let input = "key 1,key 2\nvalue 1,value 2";
+
+        let (first, cursor) = next_raw_record(input, 0).expect("it to return a value");
+        assert_eq!(first, "key 1,key 2");
+
+        let (second, cursor) = next_raw_record(input, cursor).expect("it to return a value");
+        assert_eq!(second, "value 1,value 2");
+
+        assert!(next_raw_record(input, cursor).is_none());
+    }
+
+    #[test]
+    fn it_should_honor_crlf_line_endings() {
+        let input = "key 1,key 2\r\nvalue 1,value 2\r\n";
+
+        let (first, cursor) = next_raw_record(input, 0).expect("it to return a value");
+        assert_eq!(first, "key 1,key 2");
+
+        let (second, cursor) = next_raw_record(input, cursor).expect("it to return a value");
+        assert_eq!(second, "value 1,value 2");
+
+        assert!(next_raw_record(input, cursor).is_none());
+    }
+
+    #[test]
+    fn it_should_keep_a_newline_inside_a_quoted_field_in_the_same_record() {
+        let input = "key 1,key 2\nvalue 1,\"value\n2\"";
+
+        let (_, cursor) = next_raw_record(input, 0).expect("it to return a value");
+        let (second, _) = next_raw_record(input, cursor).expect("it to return a value");
+
+        assert_eq!(second, "value 1,\"value\n2\"");
+    }
+
+    #[test]
+    fn it_should_treat_an_unclosed_quote_as_absorbing_the_rest_of_the_input() {
+        let input = "key 1,key 2\nvalue 1,\"value 2\nvalue 3,value 4";
+
+        let (_, cursor) = next_raw_record(input, 0).expect("it to return a value");
+        let (second, _) = next_raw_record(input, cursor).expect("it to return a value");
+
+        assert_eq!(second, "value 1,\"value 2\nvalue 3,value 4");
+    }
+}
+
+/// A lazy, one-record-at-a-time reader over CSV input, parsing the header
+/// once and yielding one `HashMap<String, CsvValue>` per `next()` call
+/// instead of materializing the whole file up front.
+pub struct CsvReader<'a> {
+    input: &'a str,
+    separator: char,
+    fields: Vec<String>,
+    cursor: usize,
+}
+
+impl<'a> CsvReader<'a> {
+    #[inline]
+    pub fn new(input: &'a str, separator: char) -> Option<Self> {
+        let mut cursor = 0;
+
+        loop {
+            let (record, next_cursor) = next_raw_record(input, cursor)?;
+            cursor = next_cursor;
+
+            let trimmed = record.trim();
+
+            if trimmed.is_empty() {
+                continue;
+            }
+
+            let fields = parse_header(&mut std::iter::once((0, trimmed)), separator)?;
+
+            return Some(Self {
+                input,
+                separator,
+                fields,
+                cursor,
+            });
+        }
+    }
+
+    #[inline]
+    pub fn fields(&self) -> &[String] {
+        &self.fields
+    }
+
+    /// Looks ahead `lookahead` records past the one `next()` would return
+    /// next, without consuming them. `peek(0)` returns the same record the
+    /// next `next()` call would yield.
+    #[inline]
+    pub fn peek(&mut self, lookahead: usize) -> Option<std::collections::HashMap<String, CsvValue>> {
+        let mut cursor = self.cursor;
+        let mut result = None;
+
+        for _ in 0..=lookahead {
+            result = loop {
+                let (record, next_cursor) = next_raw_record(self.input, cursor)?;
+                cursor = next_cursor;
+
+                let trimmed = record.trim();
+
+                if trimmed.is_empty() {
+                    continue;
+                }
+
+                break Some(parse_value_line(trimmed, self.separator, &self.fields));
+            };
+        }
+
+        result
+    }
+}
+
+impl<'a> Iterator for CsvReader<'a> {
+    type Item = std::collections::HashMap<String, CsvValue>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let (record, next_cursor) = next_raw_record(self.input, self.cursor)?;
+            self.cursor = next_cursor;
+
+            let trimmed = record.trim();
+
+            if trimmed.is_empty() {
+                continue;
+            }
+
+            return Some(parse_value_line(trimmed, self.separator, &self.fields));
+        }
+    }
+}
+
+#[cfg(test)]
+mod test_csv_reader {
+    use crate::{CsvReader, CsvValue};
+
+    #[test]
+    fn it_should_yield_one_record_per_call() {
+        let input = "key 1,key 2\nvalue 1,1\nvalue 2,2";
+
+        let mut reader = CsvReader::new(input, ',').expect("it to return a value");
+
+        let first = reader.next().expect("it to be some");
+        assert!(matches!(first.get("key 1"), Some(CsvValue::Text(v)) if v == "value 1"));
+
+        let second = reader.next().expect("it to be some");
+        assert!(matches!(second.get("key 2"), Some(CsvValue::Integer(2))));
+
+        assert!(reader.next().is_none());
+    }
+
+    #[test]
+    fn it_should_peek_without_consuming() {
+        let input = "key 1,key 2\nvalue 1,1\nvalue 2,2\nvalue 3,3";
+
+        let mut reader = CsvReader::new(input, ',').expect("it to return a value");
+
+        let peeked = reader
+            .peek(1)
+            .expect("it to be some")
+            .remove("key 1")
+            .expect("it to be some");
+        assert!(matches!(peeked, CsvValue::Text(v) if v == "value 2"));
+
+        let first = reader
+            .next()
+            .expect("it to be some")
+            .remove("key 1")
+            .expect("it to be some");
+        assert!(matches!(first, CsvValue::Text(v) if v == "value 1"));
+
+        let second = reader
+            .next()
+            .expect("it to be some")
+            .remove("key 1")
+            .expect("it to be some");
+        assert!(matches!(second, CsvValue::Text(v) if v == "value 2"));
+    }
+
+    #[test]
+    fn it_should_return_none_when_there_is_no_header() {
+        assert!(CsvReader::new("", ',').is_none());
+    }
+}
+
+#[inline]
+pub fn parse_csv(input: &str, separator: char) -> Vec<std::collections::HashMap<String, CsvValue>> {
+    CsvReader::new(input, separator)
+        .map(Iterator::collect)
+        .unwrap_or_default()
 }
 
 #[cfg(test)]
@@ -469,4 +1008,411 @@ mod test_parse_csv {
             }
         }
     }
+
+    #[test]
+    fn it_should_keep_a_quoted_separator_literal() {
+        let input = "key 1,key 2\n\"a,b\",c";
+
+        let result = parse_csv(input, ',');
+
+        assert_eq!(result.len(), 1);
+
+        let row = result.first().expect("it to be some");
+
+        let one = row.get("key 1").expect("it to be some");
+        assert!(matches!(one, CsvValue::Text(value) if value == "a,b"));
+
+        let two = row.get("key 2").expect("it to be some");
+        assert!(matches!(two, CsvValue::Text(value) if value == "c"));
+    }
+
+    #[test]
+    fn it_should_keep_a_newline_inside_a_quoted_field_as_part_of_the_record() {
+        let input = "key 1,key 2\n\"a\nb\",c";
+
+        let result = parse_csv(input, ',');
+
+        assert_eq!(result.len(), 1);
+
+        let row = result.first().expect("it to be some");
+
+        let one = row.get("key 1").expect("it to be some");
+        assert!(matches!(one, CsvValue::Text(value) if value == "a\nb"));
+    }
+
+    #[test]
+    fn it_should_support_crlf_line_endings() {
+        let input = "key 1,key 2\r\nvalue 1,value 2\r\n";
+
+        let result = parse_csv(input, ',');
+
+        assert_eq!(result.len(), 1);
+
+        let row = result.first().expect("it to be some");
+
+        let one = row.get("key 1").expect("it to be some");
+        assert!(matches!(one, CsvValue::Text(value) if value == "value 1"));
+
+        let two = row.get("key 2").expect("it to be some");
+        assert!(matches!(two, CsvValue::Text(value) if value == "value 2"));
+    }
+
+    #[test]
+    fn it_should_not_panic_on_a_multibyte_text_value_straddling_the_date_boundary() {
+        let input = "a,b\nx,日本語テスト";
+
+        let result = parse_csv(input, ',');
+
+        assert_eq!(result.len(), 1);
+
+        let row = result.first().expect("it to be some");
+
+        let two = row.get("b").expect("it to be some");
+        assert!(matches!(two, CsvValue::Text(value) if value == "日本語テスト"));
+    }
+}
+
+/// An inferred schema: one entry per header key, its name alongside its
+/// inferred type.
+pub type ColumnSchema = Vec<(String, ColumnType)>;
+
+/// The `(row_index, key)` of every value whose type did not match its
+/// column's inferred type.
+pub type ColumnMismatches = Vec<(usize, String)>;
+
+#[inline]
+fn infer_column_type(types: &std::collections::HashSet<ColumnType>) -> ColumnType {
+    if types.len() == 1 {
+        return *types.iter().next().expect("it to have exactly one entry");
+    }
+
+    if types.is_subset(&std::collections::HashSet::from([ColumnType::Integer, ColumnType::Float])) {
+        return ColumnType::Float;
+    }
+
+    ColumnType::Text
+}
+
+/// Infers a single `ColumnType` per header key by reconciling every row's
+/// `CsvValue` variant for that key, and reports the `(row_index, key)` of
+/// any value that does not match its column's inferred type (an `Integer`
+/// value is considered a match for a `Float` column, since it is what
+/// widened the column to `Float` in the first place).
+#[inline]
+pub fn infer_column_schema(
+    fields: &[String],
+    rows: &[std::collections::HashMap<String, CsvValue>],
+) -> (ColumnSchema, ColumnMismatches) {
+    let schema: Vec<(String, ColumnType)> = fields
+        .iter()
+        .map(|key| {
+            let types: std::collections::HashSet<ColumnType> = rows
+                .iter()
+                .filter_map(|row| row.get(key))
+                .map(value_column_type)
+                .collect();
+
+            let column_type = if types.is_empty() {
+                ColumnType::Text
+            } else {
+                infer_column_type(&types)
+            };
+
+            (key.clone(), column_type)
+        })
+        .collect();
+
+    let mut mismatches = Vec::new();
+
+    for (row_index, row) in rows.iter().enumerate() {
+        for (key, column_type) in &schema {
+            let Some(value) = row.get(key) else {
+                continue;
+            };
+
+            let value_type = value_column_type(value);
+
+            let matches_column = value_type == *column_type
+                || (*column_type == ColumnType::Float && value_type == ColumnType::Integer);
+
+            if !matches_column {
+                mismatches.push((row_index, key.clone()));
+            }
+        }
+    }
+
+    (schema, mismatches)
+}
+
+#[cfg(test)]
+mod test_infer_column_schema {
+    use crate::{infer_column_schema, ColumnType, CsvValue};
+    use std::collections::HashMap;
+
+    fn row(pairs: &[(&str, CsvValue)]) -> HashMap<String, CsvValue> {
+        pairs
+            .iter()
+            .map(|(key, value)| {
+                (
+                    (*key).to_owned(),
+                    match value {
+                        CsvValue::Integer(v) => CsvValue::Integer(*v),
+                        CsvValue::Float(v) => CsvValue::Float(*v),
+                        CsvValue::Boolean(v) => CsvValue::Boolean(*v),
+                        CsvValue::Text(v) => CsvValue::Text(v.clone()),
+                        CsvValue::DateTime(v) => CsvValue::DateTime(*v),
+                    },
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn it_should_infer_an_all_integer_column_as_integer() {
+        let fields = vec!["price".to_owned()];
+        let rows = vec![
+            row(&[("price", CsvValue::Integer(1))]),
+            row(&[("price", CsvValue::Integer(2))]),
+        ];
+
+        let (schema, mismatches) = infer_column_schema(&fields, &rows);
+
+        assert_eq!(schema, vec![("price".to_owned(), ColumnType::Integer)]);
+        assert!(mismatches.is_empty());
+    }
+
+    #[test]
+    fn it_should_widen_a_mixed_integer_and_float_column_to_float() {
+        let fields = vec!["price".to_owned()];
+        let rows = vec![
+            row(&[("price", CsvValue::Integer(1))]),
+            row(&[("price", CsvValue::Float(2.5))]),
+        ];
+
+        let (schema, mismatches) = infer_column_schema(&fields, &rows);
+
+        assert_eq!(schema, vec![("price".to_owned(), ColumnType::Float)]);
+        assert!(mismatches.is_empty());
+    }
+
+    #[test]
+    fn it_should_fall_back_to_text_for_a_heterogeneous_column() {
+        let fields = vec!["price".to_owned()];
+        let rows = vec![
+            row(&[("price", CsvValue::Integer(1))]),
+            row(&[("price", CsvValue::Text("n/a".to_owned()))]),
+        ];
+
+        let (schema, mismatches) = infer_column_schema(&fields, &rows);
+
+        assert_eq!(schema, vec![("price".to_owned(), ColumnType::Text)]);
+        assert_eq!(mismatches, vec![(0, "price".to_owned())]);
+    }
+
+    #[test]
+    fn it_should_report_the_exact_row_and_key_of_offenders() {
+        let fields = vec!["price".to_owned(), "label".to_owned()];
+        let rows = vec![
+            row(&[
+                ("price", CsvValue::Integer(1)),
+                ("label", CsvValue::Text("a".to_owned())),
+            ]),
+            row(&[
+                ("price", CsvValue::Text("oops".to_owned())),
+                ("label", CsvValue::Text("b".to_owned())),
+            ]),
+            row(&[
+                ("price", CsvValue::Integer(3)),
+                ("label", CsvValue::Text("c".to_owned())),
+            ]),
+        ];
+
+        let (schema, mismatches) = infer_column_schema(&fields, &rows);
+
+        assert_eq!(schema[0], ("price".to_owned(), ColumnType::Text));
+        assert_eq!(schema[1], ("label".to_owned(), ColumnType::Text));
+        assert_eq!(
+            mismatches,
+            vec![(0, "price".to_owned()), (2, "price".to_owned())]
+        );
+    }
+
+    #[test]
+    fn it_should_treat_a_missing_field_with_an_empty_column_as_text() {
+        let fields = vec!["price".to_owned()];
+        let rows: Vec<HashMap<String, CsvValue>> = vec![HashMap::new()];
+
+        let (schema, mismatches) = infer_column_schema(&fields, &rows);
+
+        assert_eq!(schema, vec![("price".to_owned(), ColumnType::Text)]);
+        assert!(mismatches.is_empty());
+    }
+}
+
+const SNIFF_SEPARATORS: [char; 4] = [',', ';', '\t', '|'];
+const SNIFF_SAMPLE_LINES: usize = 5;
+
+#[inline]
+fn count_occurrences_outside_quotes(line: &str, candidate: char) -> usize {
+    let mut count = 0;
+    let mut in_quotes = false;
+
+    for ch in line.chars() {
+        match ch {
+            '"' => in_quotes = !in_quotes,
+            ch if !in_quotes && ch == candidate => count += 1,
+            _ => {}
+        }
+    }
+
+    count
+}
+
+#[inline]
+fn variance(counts: &[usize]) -> f64 {
+    let mean = counts.iter().sum::<usize>() as f64 / counts.len() as f64;
+
+    counts
+        .iter()
+        .map(|count| {
+            let diff = *count as f64 - mean;
+            diff * diff
+        })
+        .sum::<f64>()
+        / counts.len() as f64
+}
+
+#[inline]
+fn sniff_separator(input: &str) -> char {
+    let mut sample_lines = Vec::new();
+    let mut cursor = 0;
+
+    while sample_lines.len() < SNIFF_SAMPLE_LINES {
+        let Some((record, next_cursor)) = next_raw_record(input, cursor) else {
+            break;
+        };
+
+        cursor = next_cursor;
+
+        let trimmed = record.trim();
+
+        if !trimmed.is_empty() {
+            sample_lines.push(trimmed);
+        }
+    }
+
+    let mut best: Option<(char, f64)> = None;
+    let mut tied = false;
+
+    for candidate in SNIFF_SEPARATORS {
+        let counts: Vec<usize> = sample_lines
+            .iter()
+            .map(|line| count_occurrences_outside_quotes(line, candidate))
+            .collect();
+
+        if counts.contains(&0) {
+            continue;
+        }
+
+        let score = variance(&counts);
+
+        let is_better = match best {
+            Some((_, best_score)) => score < best_score,
+            None => true,
+        };
+
+        if is_better {
+            best = Some((candidate, score));
+            tied = false;
+        } else if best.is_some_and(|(_, best_score)| score == best_score) {
+            tied = true;
+        }
+    }
+
+    if tied {
+        return ',';
+    }
+
+    best.map_or(',', |(candidate, _)| candidate)
+}
+
+/// Detects the delimiter among `,`, `;`, `\t` and `|` by sampling the first
+/// few non-empty lines of `input` and picking the candidate whose per-line
+/// occurrence count (outside quoted fields) is both non-zero and most
+/// consistent across those lines. Falls back to `,` when the input is
+/// ambiguous or single-column.
+#[inline]
+pub fn parse_csv_auto(
+    input: &str,
+) -> (char, Vec<std::collections::HashMap<String, CsvValue>>) {
+    let separator = sniff_separator(input);
+
+    (separator, parse_csv(input, separator))
+}
+
+#[cfg(test)]
+mod test_parse_csv_auto {
+    use crate::{parse_csv_auto, CsvValue};
+
+    #[test]
+    fn it_should_detect_a_comma_separated_file() {
+        let input = "key 1,key 2,key 3\n1,2,3\n4,5,6\n7,8,9";
+
+        let (separator, rows) = parse_csv_auto(input);
+
+        assert_eq!(separator, ',');
+        assert_eq!(rows.len(), 3);
+    }
+
+    #[test]
+    fn it_should_detect_a_semicolon_separated_file() {
+        let input = "key 1;key 2;key 3\n1;2;3\n4;5;6\n7;8;9";
+
+        let (separator, rows) = parse_csv_auto(input);
+
+        assert_eq!(separator, ';');
+        assert_eq!(rows.len(), 3);
+    }
+
+    #[test]
+    fn it_should_detect_a_tab_separated_file() {
+        let input = "key 1\tkey 2\tkey 3\n1\t2\t3\n4\t5\t6";
+
+        let (separator, rows) = parse_csv_auto(input);
+
+        assert_eq!(separator, '\t');
+        assert_eq!(rows.len(), 2);
+    }
+
+    #[test]
+    fn it_should_detect_a_pipe_separated_file() {
+        let input = "key 1|key 2|key 3\n1|2|3\n4|5|6";
+
+        let (separator, rows) = parse_csv_auto(input);
+
+        assert_eq!(separator, '|');
+        assert_eq!(rows.len(), 2);
+    }
+
+    #[test]
+    fn it_should_ignore_a_separator_candidate_appearing_inside_quotes() {
+        let input = "key 1;key 2\n\"a,b\";c\n\"d,e\";f";
+
+        let (separator, rows) = parse_csv_auto(input);
+
+        assert_eq!(separator, ';');
+
+        let row = rows.first().expect("it to be some");
+        let one = row.get("key 1").expect("it to be some");
+        assert!(matches!(one, CsvValue::Text(value) if value == "a,b"));
+    }
+
+    #[test]
+    fn it_should_fall_back_to_comma_for_single_column_input() {
+        let input = "key 1\nvalue 1\nvalue 2";
+
+        let (separator, _) = parse_csv_auto(input);
+
+        assert_eq!(separator, ',');
+    }
 }